@@ -2,12 +2,17 @@ use alvr_common::{LogEntry, LogSeverity};
 use alvr_gui_common::theme::{self, log_colors};
 use alvr_session::Settings;
 use eframe::{
-    egui::{self, Frame, Label, Layout, RichText, TextWrapMode, TopBottomPanel},
+    egui::{
+        self, ComboBox, Frame, Label, Layout, RichText, ScrollArea, TextWrapMode, TopBottomPanel,
+    },
     emath::Align,
-    epaint::Color32,
+    epaint::{Color32, Hsva},
 };
 use rand::seq::IndexedRandom;
-use std::time::Duration;
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    time::Duration,
+};
 
 #[cfg(target_arch = "wasm32")]
 use instant::Instant;
@@ -16,55 +21,315 @@ use std::time::Instant;
 
 const TIMEOUT: Duration = Duration::from_secs(5);
 const NO_NOTIFICATIONS_MESSAGE: &str = "No new notifications";
-const NOTIFICATION_TIPS: &[&str] = &[
+const DEFAULT_HISTORY_CAPACITY: usize = 100;
+
+/// A path of tab/submenu/setting names as they appear in the dashboard, used to deep-link a
+/// notification to the control it refers to, e.g. `&["Video", "Maximum buffering"]`.
+pub type SettingsPath = &'static [&'static str];
+
+/// A tip shown in the notification bar. `target` is populated when the tip references a
+/// concrete setting, allowing a "Go to setting" action to be rendered alongside it.
+struct Tip {
+    text: &'static str,
+    target: Option<SettingsPath>,
+}
+
+const fn tip(text: &'static str) -> Tip {
+    Tip { text, target: None }
+}
+
+const fn tip_at(text: &'static str, target: SettingsPath) -> Tip {
+    Tip {
+        text,
+        target: Some(target),
+    }
+}
+
+const NOTIFICATION_TIPS: &[Tip] = &[
     // The following tips are ordered roughtly in the order settings appear
-    r#"If you started having crashes after changing some settings, reset ALVR by re-running "Run setup wizard" from the "Installation" tab and clicking "Reset settings"."#,
-    r#"Some settings are hidden by default. Click the "Expand" button next to some settings to expand the submenus."#,
-    r#"It's highly advisable to keep audio settings as default in ALVR and modify the default audio device in the taskbar tray."#,
-    r#"Increasing "Video"->"Maximum buffering" may reduce stutters at the cost of more latency."#,
-    r#"Sometimes switching between h264 and HEVC codecs is necessary on certain GPUs to fix crashing or fallback to software encoding."#,
-    r#"If you're using an NVIDIA GPU, it's best to use high-bitrate H264; if you're using an AMD GPU, HEVC might look better."#,
-    r#"If you experience "white snow" flickering, set "Presets"->"Resolution" to "Low" and disable "Video"->"Foveated encoding"."#,
-    r#"Increasing "Video"->"Color correction"->"Sharpness" may improve the perceived image quality."#,
-    r#"If you have problems syncing external controllers or trackers to ALVR tracking space, add one element to "Headset"->"Extra OpenVR properties", then set a custom "Tracking system name string"."#,
-    r#"To change the visual appearance of controllers, set "Headset"->"Controllers"->"Emulation mode"."#,
-    r#"ALVR supports custom button bindings! If you need help, please ask us on our Discord server."#,
-    r#"ALVR supports hand tracking gestures ("Presets"->"Hand tracking interaction"->"ALVR bindings"). Check out wiki how to use them properly: https://github.com/alvr-org/ALVR/wiki/Hand-tracking-controller-bindings."#,
-    r#"If hand tracking gestures are annoying, you can disable them in "Headset"->"Controllers"->"Hand tracking interaction". Alternatively, you can enable "Hand tracking interaction"->"Only touch"."#,
-    r#"You can fine-tune the controllers' responsiveness with "Headset"->"Controllers"->"Prediction"."#,
-    r#"If the visual controller/hand models do not match the physical controller's position, you can tweak the offset in "Headset"->"Controllers"->"Left controller position/rotation offset" (affects both controllers)."#,
-    r#"When using external trackers or controllers, you should set both "Headset"->"Position/Rotation recentering mode" to "Disabled"."#,
-    r#"You can enable tilt mode. Set "Headset"->"Position recentering mode" to "Local" and "Headset"->"Rotation recentering mode" to "Tilted"."#,
-    r#"If you often experience image glitching, you can trade that with stutter frames using "Connection"->"Avoid video glitching"."#,
-    r#"You can run custom commands/programs at headset connection/disconnection using "Connection"->"Enable on connect/disconnect script"."#,
-    r#"In case you want to report a bug, to get a log file, enable "Extra"->"Logging"->"Log to disk". The log will be inside "session_log.txt"."#,
-    r#"For hacking purposes, you can enable "Extra"->"Logging"->"Log tracking", "Log button presses" and "Log haptics". You can get the data using a websocket at ws://localhost:8082/api/events."#,
-    r#"In case you want to report a bug and share your log, you should enable "Extra"->"Logging"->"Prefer backtrace"."#,
-    r#"You can quickly cycle through tips like this one by toggling "Extra"->"Logging"->"Show notification tip"."#,
-    r#"It's handy to enable "Extra"->"SteamVR Launcher"->"Open and close SteamVR automatically"."#,
-    r#"If you want to share a video recording for reporting a bug, you can enable "Extra"->"Capture"->"Rolling video files" to limit the file size of the upload."#,
+    tip(
+        r#"If you started having crashes after changing some settings, reset ALVR by re-running "Run setup wizard" from the "Installation" tab and clicking "Reset settings"."#,
+    ),
+    tip(
+        r#"Some settings are hidden by default. Click the "Expand" button next to some settings to expand the submenus."#,
+    ),
+    tip(
+        r#"It's highly advisable to keep audio settings as default in ALVR and modify the default audio device in the taskbar tray."#,
+    ),
+    tip_at(
+        r#"Increasing "Video"->"Maximum buffering" may reduce stutters at the cost of more latency."#,
+        &["Video", "Maximum buffering"],
+    ),
+    tip(
+        r#"Sometimes switching between h264 and HEVC codecs is necessary on certain GPUs to fix crashing or fallback to software encoding."#,
+    ),
+    tip(
+        r#"If you're using an NVIDIA GPU, it's best to use high-bitrate H264; if you're using an AMD GPU, HEVC might look better."#,
+    ),
+    tip_at(
+        r#"If you experience "white snow" flickering, set "Presets"->"Resolution" to "Low" and disable "Video"->"Foveated encoding"."#,
+        &["Video", "Foveated encoding"],
+    ),
+    tip_at(
+        r#"Increasing "Video"->"Color correction"->"Sharpness" may improve the perceived image quality."#,
+        &["Video", "Color correction", "Sharpness"],
+    ),
+    tip_at(
+        r#"If you have problems syncing external controllers or trackers to ALVR tracking space, add one element to "Headset"->"Extra OpenVR properties", then set a custom "Tracking system name string"."#,
+        &["Headset", "Extra OpenVR properties"],
+    ),
+    tip_at(
+        r#"To change the visual appearance of controllers, set "Headset"->"Controllers"->"Emulation mode"."#,
+        &["Headset", "Controllers", "Emulation mode"],
+    ),
+    tip(
+        r#"ALVR supports custom button bindings! If you need help, please ask us on our Discord server."#,
+    ),
+    tip_at(
+        r#"ALVR supports hand tracking gestures ("Presets"->"Hand tracking interaction"->"ALVR bindings"). Check out wiki how to use them properly: https://github.com/alvr-org/ALVR/wiki/Hand-tracking-controller-bindings."#,
+        &["Presets", "Hand tracking interaction", "ALVR bindings"],
+    ),
+    tip_at(
+        r#"If hand tracking gestures are annoying, you can disable them in "Headset"->"Controllers"->"Hand tracking interaction". Alternatively, you can enable "Hand tracking interaction"->"Only touch"."#,
+        &["Headset", "Controllers", "Hand tracking interaction"],
+    ),
+    tip_at(
+        r#"You can fine-tune the controllers' responsiveness with "Headset"->"Controllers"->"Prediction"."#,
+        &["Headset", "Controllers", "Prediction"],
+    ),
+    tip_at(
+        r#"If the visual controller/hand models do not match the physical controller's position, you can tweak the offset in "Headset"->"Controllers"->"Left controller position/rotation offset" (affects both controllers)."#,
+        &[
+            "Headset",
+            "Controllers",
+            "Left controller position/rotation offset",
+        ],
+    ),
+    tip_at(
+        r#"When using external trackers or controllers, you should set both "Headset"->"Position/Rotation recentering mode" to "Disabled"."#,
+        &["Headset", "Position/Rotation recentering mode"],
+    ),
+    tip_at(
+        r#"You can enable tilt mode. Set "Headset"->"Position recentering mode" to "Local" and "Headset"->"Rotation recentering mode" to "Tilted"."#,
+        &["Headset", "Position recentering mode"],
+    ),
+    tip_at(
+        r#"If you often experience image glitching, you can trade that with stutter frames using "Connection"->"Avoid video glitching"."#,
+        &["Connection", "Avoid video glitching"],
+    ),
+    tip_at(
+        r#"You can run custom commands/programs at headset connection/disconnection using "Connection"->"Enable on connect/disconnect script"."#,
+        &["Connection", "Enable on connect/disconnect script"],
+    ),
+    tip_at(
+        r#"In case you want to report a bug, to get a log file, enable "Extra"->"Logging"->"Log to disk". The log will be inside "session_log.txt"."#,
+        &["Extra", "Logging", "Log to disk"],
+    ),
+    tip_at(
+        r#"For hacking purposes, you can enable "Extra"->"Logging"->"Log tracking", "Log button presses" and "Log haptics". You can get the data using a websocket at ws://localhost:8082/api/events."#,
+        &["Extra", "Logging", "Log tracking"],
+    ),
+    tip_at(
+        r#"In case you want to report a bug and share your log, you should enable "Extra"->"Logging"->"Prefer backtrace"."#,
+        &["Extra", "Logging", "Prefer backtrace"],
+    ),
+    tip_at(
+        r#"You can quickly cycle through tips like this one by toggling "Extra"->"Logging"->"Show notification tip"."#,
+        &["Extra", "Logging", "Show notification tip"],
+    ),
+    tip_at(
+        r#"It's handy to enable "Extra"->"SteamVR Launcher"->"Open and close SteamVR automatically"."#,
+        &[
+            "Extra",
+            "SteamVR Launcher",
+            "Open and close SteamVR automatically",
+        ],
+    ),
+    tip_at(
+        r#"If you want to share a video recording for reporting a bug, you can enable "Extra"->"Capture"->"Rolling video files" to limit the file size of the upload."#,
+        &["Extra", "Capture", "Rolling video files"],
+    ),
     // Miscellaneous
-    r#"If your headset does not appear in the device list, it might be in a different subnet. Try "Add device manually" with IP shown from inside device."#,
+    tip(
+        r#"If your headset does not appear in the device list, it might be in a different subnet. Try "Add device manually" with IP shown from inside device."#,
+    ),
+];
+
+/// Emitted by [`NotificationBar::ui`] when the user clicks the "Go to setting" action on a tip
+/// or notification. The dashboard is expected to switch to the tab named by the first path
+/// segment, then expand any collapsed submenus along the remaining segments.
+pub struct NavigationRequest {
+    pub path: Vec<String>,
+}
+
+/// Top-level dashboard tabs, as referenced by [`NOTIFICATION_TIPS`]. A message is only treated
+/// as a navigable settings path if its first segment names one of these; otherwise unrelated
+/// text that happens to contain a quoted `"a"->"b"` pair (e.g. a future pipeline-stage error)
+/// would be misdetected as a "Go to setting" target.
+const KNOWN_SETTINGS_TABS: &[&str] = &[
+    "Presets",
+    "Connection",
+    "Video",
+    "Headset",
+    "Extra",
+    "Installation",
 ];
 
+/// Best-effort extraction of a settings path out of a message written with the same
+/// `"Tab"->"Submenu"->"Setting"` convention used throughout [`NOTIFICATION_TIPS`], so that
+/// errors and warnings emitted elsewhere in the app can also be deep-linked. Returns `None`
+/// unless the first segment names a tab in [`KNOWN_SETTINGS_TABS`].
+fn parse_settings_path(text: &str) -> Option<Vec<String>> {
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('"') {
+        let after_quote = &rest[start + 1..];
+        let Some(end) = after_quote.find('"') else {
+            break;
+        };
+        let segment = &after_quote[..end];
+        rest = &after_quote[end + 1..];
+
+        if let Some(tail) = rest.strip_prefix("->") {
+            segments.push(segment.to_owned());
+            rest = tail;
+        } else if !segments.is_empty() {
+            segments.push(segment.to_owned());
+            break;
+        }
+        // Else this quoted segment isn't the start of an arrow-chain (e.g. an unrelated quoted
+        // filename earlier in the message) — keep scanning `rest` for a later one instead of
+        // giving up on the whole message.
+    }
+
+    if !KNOWN_SETTINGS_TABS.contains(&segments.first()?.as_str()) {
+        return None;
+    }
+
+    Some(segments)
+}
+
+/// The minimum severity a notification must have to be accepted, given the origin it came from.
+/// A per-device override (set via [`NotificationBar::set_device_min_notification_level`]) takes
+/// priority over the global `min_notification_level`.
+fn effective_min_severity(
+    from_dashboard: bool,
+    device_id: Option<&str>,
+    per_device_min_notification_level: &HashMap<String, LogSeverity>,
+    min_notification_level: LogSeverity,
+) -> LogSeverity {
+    if from_dashboard {
+        if cfg!(debug_assertions) {
+            LogSeverity::Debug
+        } else {
+            LogSeverity::Info
+        }
+    } else {
+        device_id
+            .and_then(|id| per_device_min_notification_level.get(id))
+            .copied()
+            .unwrap_or(min_notification_level)
+    }
+}
+
+fn severity_colors(severity: LogSeverity) -> (Color32, Color32) {
+    match severity {
+        LogSeverity::Error => (Color32::BLACK, log_colors::ERROR_LIGHT),
+        LogSeverity::Warning => (Color32::BLACK, log_colors::WARNING_LIGHT),
+        LogSeverity::Info => (Color32::BLACK, log_colors::INFO_LIGHT),
+        LogSeverity::Debug => (theme::FG, theme::LIGHTER_BG),
+    }
+}
+
+fn format_elapsed(now: Instant, at: Instant) -> String {
+    let secs = now.duration_since(at).as_secs();
+    if secs == 0 {
+        "just now".into()
+    } else {
+        format!("{secs}s ago")
+    }
+}
+
+/// Deterministic color for a device identifier, so the same device is always tagged with the
+/// same color across the collapsed bar, the history list and the device filter.
+fn device_color(device_id: &str) -> Color32 {
+    let hash = device_id.bytes().fold(0u32, |acc, byte| {
+        acc.wrapping_mul(31).wrapping_add(byte as u32)
+    });
+    let hue = (hash % 360) as f32 / 360.0;
+    Color32::from(Hsva::new(hue, 0.6, 0.55, 1.0))
+}
+
+/// A single past notification kept around after the collapsed bar has moved on, so the
+/// expanded view can show more than just the latest message.
+struct HistoryEntry {
+    content: String,
+    target: Option<Vec<String>>,
+    severity: LogSeverity,
+    received_at: Instant,
+    device_id: Option<String>,
+}
+
 pub struct NotificationBar {
     message: String,
+    message_target: Option<Vec<String>>,
+    message_device_id: Option<String>,
     current_level: LogSeverity,
     receive_instant: Instant,
     min_notification_level: LogSeverity,
-    tip_message: Option<String>,
+    per_device_min_notification_level: HashMap<String, LogSeverity>,
+    tip_message: Option<(String, Option<SettingsPath>)>,
     expanded: bool,
+    history: VecDeque<HistoryEntry>,
+    history_capacity: usize,
+    history_filter: Option<LogSeverity>,
+    device_filter: Option<String>,
 }
 
 impl NotificationBar {
     pub fn new() -> Self {
         Self {
             message: NO_NOTIFICATIONS_MESSAGE.into(),
+            message_target: None,
+            message_device_id: None,
             current_level: LogSeverity::Debug,
             receive_instant: Instant::now(),
             min_notification_level: LogSeverity::Debug,
+            per_device_min_notification_level: HashMap::new(),
             tip_message: None,
             expanded: false,
+            history: VecDeque::new(),
+            history_capacity: DEFAULT_HISTORY_CAPACITY,
+            history_filter: None,
+            device_filter: None,
+        }
+    }
+
+    /// Sets how many past notifications are kept in the expanded history view, dropping the
+    /// oldest entries if the new capacity is smaller than the current history.
+    pub fn set_history_capacity(&mut self, capacity: usize) {
+        self.history_capacity = capacity;
+        while self.history.len() > self.history_capacity {
+            self.history.pop_front();
+        }
+    }
+
+    /// Overrides `min_notification_level` for a single device, so a noisy secondary headset can
+    /// be quieted without silencing the primary one. Passing `None` falls back to the global
+    /// level set by [`Self::update_settings`].
+    pub fn set_device_min_notification_level(
+        &mut self,
+        device_id: String,
+        level: Option<LogSeverity>,
+    ) {
+        match level {
+            Some(level) => {
+                self.per_device_min_notification_level
+                    .insert(device_id, level);
+            }
+            None => {
+                self.per_device_min_notification_level.remove(&device_id);
+            }
         }
     }
 
@@ -75,50 +340,79 @@ impl NotificationBar {
             if self.tip_message.is_none() {
                 self.tip_message = NOTIFICATION_TIPS
                     .choose(&mut rand::rng())
-                    .map(|s| format!("Tip: {s}"));
+                    .map(|tip| (format!("Tip: {}", tip.text), tip.target));
             }
         } else {
             self.tip_message = None;
         }
     }
 
-    pub fn push_notification(&mut self, event: LogEntry, from_dashboard: bool) {
+    /// `device_id` identifies which connected headset this notification came from, when ALVR is
+    /// managing more than one. Pass `None` for notifications not tied to a specific device.
+    pub fn push_notification(
+        &mut self,
+        event: LogEntry,
+        from_dashboard: bool,
+        device_id: Option<String>,
+    ) {
         let now = Instant::now();
-        let min_severity = if from_dashboard {
-            if cfg!(debug_assertions) {
-                LogSeverity::Debug
-            } else {
-                LogSeverity::Info
+        let min_severity = effective_min_severity(
+            from_dashboard,
+            device_id.as_deref(),
+            &self.per_device_min_notification_level,
+            self.min_notification_level,
+        );
+
+        if event.severity >= min_severity {
+            let target = parse_settings_path(&event.content);
+
+            if now > self.receive_instant + TIMEOUT || event.severity >= self.current_level {
+                self.message_target = target.clone();
+                self.message_device_id = device_id.clone();
+                self.message = event.content.clone();
+                self.current_level = event.severity;
+                self.receive_instant = now;
             }
-        } else {
-            self.min_notification_level
-        };
 
-        if event.severity >= min_severity
-            && (now > self.receive_instant + TIMEOUT || event.severity >= self.current_level)
-        {
-            self.message = event.content;
-            self.current_level = event.severity;
-            self.receive_instant = now;
+            self.history.push_back(HistoryEntry {
+                content: event.content,
+                target,
+                severity: event.severity,
+                received_at: now,
+                device_id,
+            });
+            if self.history.len() > self.history_capacity {
+                self.history.pop_front();
+            }
         }
     }
 
-    pub fn ui(&mut self, context: &egui::Context) {
+    /// Removes every entry from the notification history.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Renders the notification bar, returning a [`NavigationRequest`] if the user clicked
+    /// "Go to setting" on the current tip or notification, or on an entry of the expanded history.
+    pub fn ui(&mut self, context: &egui::Context) -> Option<NavigationRequest> {
         let now = Instant::now();
         if now > self.receive_instant + TIMEOUT {
-            self.message = self
-                .tip_message
-                .clone()
-                .unwrap_or_else(|| NO_NOTIFICATIONS_MESSAGE.into());
+            match &self.tip_message {
+                Some((text, target)) => {
+                    self.message = text.clone();
+                    self.message_target =
+                        target.map(|path| path.iter().map(|s| s.to_string()).collect());
+                }
+                None => {
+                    self.message = NO_NOTIFICATIONS_MESSAGE.into();
+                    self.message_target = None;
+                }
+            }
+            self.message_device_id = None;
             self.current_level = LogSeverity::Debug;
         }
 
-        let (fg, bg) = match self.current_level {
-            LogSeverity::Error => (Color32::BLACK, log_colors::ERROR_LIGHT),
-            LogSeverity::Warning => (Color32::BLACK, log_colors::WARNING_LIGHT),
-            LogSeverity::Info => (Color32::BLACK, log_colors::INFO_LIGHT),
-            LogSeverity::Debug => (theme::FG, theme::LIGHTER_BG),
-        };
+        let (fg, bg) = severity_colors(self.current_level);
 
         let mut bottom_bar = TopBottomPanel::bottom("bottom_panel").frame(
             Frame::default()
@@ -138,23 +432,262 @@ impl NotificationBar {
             TextWrapMode::Wrap
         };
 
+        let mut navigation_request = None;
+
         bottom_bar.show(context, |ui| {
-            ui.with_layout(Layout::right_to_left(alignment), |ui| {
-                if !self.expanded {
-                    if ui.small_button("Expand").clicked() {
-                        self.expanded = true;
+            ui.vertical(|ui| {
+                ui.with_layout(Layout::right_to_left(alignment), |ui| {
+                    if !self.expanded {
+                        if ui.small_button("Expand").clicked() {
+                            self.expanded = true;
+                        }
+                    } else {
+                        if ui.button("Reduce").clicked() {
+                            self.expanded = false;
+                        }
+                        if ui.button("Clear").clicked() {
+                            self.history.clear();
+                        }
+                        ComboBox::from_id_salt("notification_history_filter")
+                            .selected_text(match self.history_filter {
+                                None => "All severities".to_owned(),
+                                Some(severity) => format!("{severity:?} and above"),
+                            })
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut self.history_filter,
+                                    None,
+                                    "All severities",
+                                );
+                                for severity in [
+                                    LogSeverity::Debug,
+                                    LogSeverity::Info,
+                                    LogSeverity::Warning,
+                                    LogSeverity::Error,
+                                ] {
+                                    ui.selectable_value(
+                                        &mut self.history_filter,
+                                        Some(severity),
+                                        format!("{severity:?} and above"),
+                                    );
+                                }
+                            });
+                        let known_devices: BTreeSet<&String> = self
+                            .history
+                            .iter()
+                            .filter_map(|entry| entry.device_id.as_ref())
+                            .collect();
+                        if !known_devices.is_empty() {
+                            ComboBox::from_id_salt("notification_device_filter")
+                                .selected_text(
+                                    self.device_filter
+                                        .clone()
+                                        .unwrap_or_else(|| "All devices".to_owned()),
+                                )
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(
+                                        &mut self.device_filter,
+                                        None,
+                                        "All devices",
+                                    );
+                                    for device_id in known_devices {
+                                        ui.selectable_value(
+                                            &mut self.device_filter,
+                                            Some(device_id.clone()),
+                                            device_id.as_str(),
+                                        );
+                                    }
+                                });
+                        }
                     }
-                } else if ui.button("Reduce").clicked() {
-                    self.expanded = false;
+                    if let Some(path) = &self.message_target {
+                        if ui.small_button("Go to setting").clicked() {
+                            navigation_request = Some(NavigationRequest { path: path.clone() });
+                        }
+                    }
+                    ui.with_layout(Layout::left_to_right(alignment), |ui| {
+                        if let Some(device_id) = &self.message_device_id {
+                            ui.label(
+                                RichText::new(device_id.as_str())
+                                    .color(device_color(device_id))
+                                    .strong()
+                                    .size(12.0),
+                            );
+                        }
+                        //A LayoutJob that has its TextWrapping updated to fill the available space would probably be a more elegant solution.
+                        ui.add(
+                            Label::new(RichText::new(&self.message).color(fg).size(12.0))
+                                .wrap_mode(wrapping),
+                        );
+                    })
+                });
+
+                if self.expanded {
+                    let history_filter = self.history_filter;
+                    let device_filter = self.device_filter.clone();
+                    ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                        for entry in self.history.iter().rev().filter(|entry| {
+                            history_filter.is_none_or(|min| entry.severity >= min)
+                                && device_filter.as_ref().is_none_or(|device_id| {
+                                    entry.device_id.as_ref() == Some(device_id)
+                                })
+                        }) {
+                            let (entry_fg, entry_bg) = severity_colors(entry.severity);
+                            Frame::default()
+                                .fill(entry_bg)
+                                .inner_margin(egui::vec2(6.0, 3.0))
+                                .show(ui, |ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.label(
+                                            RichText::new(format_elapsed(now, entry.received_at))
+                                                .color(entry_fg)
+                                                .size(10.0),
+                                        );
+                                        if let Some(device_id) = &entry.device_id {
+                                            ui.label(
+                                                RichText::new(device_id.as_str())
+                                                    .color(device_color(device_id))
+                                                    .strong()
+                                                    .size(10.0),
+                                            );
+                                        }
+                                        ui.label(
+                                            RichText::new(&entry.content)
+                                                .color(entry_fg)
+                                                .size(12.0),
+                                        );
+                                        if let Some(path) = &entry.target {
+                                            if ui.small_button("Go to setting").clicked() {
+                                                navigation_request =
+                                                    Some(NavigationRequest { path: path.clone() });
+                                            }
+                                        }
+                                    });
+                                });
+                        }
+                    });
                 }
-                ui.with_layout(Layout::left_to_right(alignment), |ui| {
-                    //A LayoutJob that has its TextWrapping updated to fill the available space would probably be a more elegant solution.
-                    ui.add(
-                        Label::new(RichText::new(&self.message).color(fg).size(12.0))
-                            .wrap_mode(wrapping),
-                    );
-                })
             })
         });
+
+        navigation_request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_settings_path_accepts_a_known_tab() {
+        assert_eq!(
+            parse_settings_path(r#"Increasing "Video"->"Maximum buffering" may reduce stutters."#),
+            Some(vec!["Video".to_owned(), "Maximum buffering".to_owned()])
+        );
+    }
+
+    #[test]
+    fn parse_settings_path_accepts_a_deeper_known_tab_path() {
+        assert_eq!(
+            parse_settings_path(
+                r#"Set "Headset"->"Controllers"->"Emulation mode" to change this."#
+            ),
+            Some(vec![
+                "Headset".to_owned(),
+                "Controllers".to_owned(),
+                "Emulation mode".to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_settings_path_rejects_an_unknown_first_segment() {
+        // Looks like a settings path but "decode" isn't a dashboard tab.
+        assert_eq!(
+            parse_settings_path(r#"Error in "decode"->"encode" pipeline stage."#),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_settings_path_skips_an_unrelated_quoted_segment_before_the_real_path() {
+        assert_eq!(
+            parse_settings_path(
+                r#"Could not read preset "foo.json", please check "Video"->"Bitrate"."#
+            ),
+            Some(vec!["Video".to_owned(), "Bitrate".to_owned()])
+        );
+    }
+
+    #[test]
+    fn parse_settings_path_rejects_text_with_no_arrow_separated_quotes() {
+        assert_eq!(
+            parse_settings_path(r#"The log will be inside "session_log.txt"."#),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_settings_path_rejects_plain_text() {
+        assert_eq!(parse_settings_path("Everything is fine."), None);
+    }
+
+    #[test]
+    fn effective_min_severity_from_dashboard_ignores_device_overrides() {
+        let mut overrides = HashMap::new();
+        overrides.insert("headset-a".to_owned(), LogSeverity::Error);
+
+        let expected = if cfg!(debug_assertions) {
+            LogSeverity::Debug
+        } else {
+            LogSeverity::Info
+        };
+
+        assert_eq!(
+            effective_min_severity(true, Some("headset-a"), &overrides, LogSeverity::Warning),
+            expected
+        );
+    }
+
+    #[test]
+    fn effective_min_severity_uses_per_device_override_when_present() {
+        let mut overrides = HashMap::new();
+        overrides.insert("headset-a".to_owned(), LogSeverity::Error);
+
+        assert_eq!(
+            effective_min_severity(false, Some("headset-a"), &overrides, LogSeverity::Info),
+            LogSeverity::Error
+        );
+    }
+
+    #[test]
+    fn effective_min_severity_falls_back_to_global_for_unknown_device() {
+        let mut overrides = HashMap::new();
+        overrides.insert("headset-a".to_owned(), LogSeverity::Error);
+
+        assert_eq!(
+            effective_min_severity(false, Some("headset-b"), &overrides, LogSeverity::Warning),
+            LogSeverity::Warning
+        );
+    }
+
+    #[test]
+    fn effective_min_severity_falls_back_to_global_when_no_device_id() {
+        let overrides = HashMap::new();
+
+        assert_eq!(
+            effective_min_severity(false, None, &overrides, LogSeverity::Warning),
+            LogSeverity::Warning
+        );
+    }
+
+    #[test]
+    fn device_color_is_deterministic_for_the_same_device_id() {
+        assert_eq!(device_color("headset-a"), device_color("headset-a"));
+    }
+
+    #[test]
+    fn device_color_differs_across_device_ids() {
+        assert_ne!(device_color("headset-a"), device_color("headset-b"));
     }
 }
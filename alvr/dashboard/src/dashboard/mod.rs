@@ -0,0 +1,99 @@
+mod components;
+
+use components::notifications::NavigationRequest;
+use eframe::egui;
+use std::collections::HashSet;
+
+pub use components::notifications::NotificationBar;
+
+/// Tracks which dashboard tab is showing and which settings submenus are expanded, so a
+/// notification's "Go to setting" action can jump straight to the referenced control.
+///
+/// Submenus are keyed by their full path joined with `->` (e.g. `"Headset->Controllers"`), since
+/// the same submenu name can appear under more than one tab.
+#[derive(Default)]
+pub struct NavigationState {
+    pub current_tab: Option<String>,
+    pub expanded_submenus: HashSet<String>,
+}
+
+impl NavigationState {
+    /// Switches to the tab named by the first path segment, then expands every submenu between
+    /// the tab and the leaf control so the referenced setting is visible.
+    pub fn apply(&mut self, request: NavigationRequest) {
+        let mut segments = request.path.into_iter();
+        let Some(tab) = segments.next() else {
+            return;
+        };
+
+        let mut prefix = tab.clone();
+        self.current_tab = Some(tab);
+
+        let submenus: Vec<String> = segments.collect();
+        if let Some((_leaf_control, ancestor_submenus)) = submenus.split_last() {
+            for submenu in ancestor_submenus {
+                prefix = format!("{prefix}->{submenu}");
+                self.expanded_submenus.insert(prefix.clone());
+            }
+        }
+    }
+}
+
+/// Owns the notification bar and the navigation state it can drive.
+pub struct Dashboard {
+    notification_bar: NotificationBar,
+    pub navigation: NavigationState,
+}
+
+impl Dashboard {
+    pub fn new() -> Self {
+        Self {
+            notification_bar: NotificationBar::new(),
+            navigation: NavigationState::default(),
+        }
+    }
+
+    pub fn ui(&mut self, context: &egui::Context) {
+        if let Some(request) = self.notification_bar.ui(context) {
+            self.navigation.apply(request);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_switches_tab_and_expands_ancestor_submenus() {
+        let mut navigation = NavigationState::default();
+
+        navigation.apply(NavigationRequest {
+            path: vec![
+                "Headset".to_owned(),
+                "Controllers".to_owned(),
+                "Emulation mode".to_owned(),
+            ],
+        });
+
+        assert_eq!(navigation.current_tab.as_deref(), Some("Headset"));
+        assert!(navigation
+            .expanded_submenus
+            .contains("Headset->Controllers"));
+        assert!(!navigation
+            .expanded_submenus
+            .contains("Headset->Controllers->Emulation mode"));
+    }
+
+    #[test]
+    fn apply_with_only_a_tab_and_leaf_expands_nothing() {
+        let mut navigation = NavigationState::default();
+
+        navigation.apply(NavigationRequest {
+            path: vec!["Video".to_owned(), "Maximum buffering".to_owned()],
+        });
+
+        assert_eq!(navigation.current_tab.as_deref(), Some("Video"));
+        assert!(navigation.expanded_submenus.is_empty());
+    }
+}